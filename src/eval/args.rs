@@ -1,11 +1,11 @@
 use std::fmt::{self, Debug, Formatter};
 
-use ecow::{eco_format, EcoVec};
+use ecow::{eco_format, EcoString, EcoVec};
 
-use super::{Array, Cast, Dict, Str, Value};
+use super::{Array, Cast, Dict, ParamInfo, Str, Value};
 use crate::diag::{bail, At, SourceResult};
 use crate::syntax::{Span, Spanned};
-use crate::util::pretty_array_like;
+use crate::util::{pretty_array_like, separated_list};
 
 /// Evaluated arguments to a function.
 #[derive(Clone, PartialEq, Hash)]
@@ -61,6 +61,31 @@ impl Args {
         Ok(None)
     }
 
+    /// Consume and cast the first positional argument, but only if it is
+    /// castable and the predicate holds for it. Otherwise, it stays in place.
+    pub fn eat_if<T>(&mut self, pred: impl FnOnce(&T) -> bool) -> SourceResult<Option<T>>
+    where
+        T: Cast<Spanned<Value>>,
+    {
+        let Some(i) = self.items.iter().position(|slot| slot.name.is_none()) else {
+            return Ok(None);
+        };
+
+        let value = &self.items[i].value;
+        if !T::is(value) {
+            return Ok(None);
+        }
+
+        let span = value.span;
+        let cast = T::cast(value.clone()).at(span)?;
+        if !pred(&cast) {
+            return Ok(None);
+        }
+
+        self.items.remove(i);
+        Ok(Some(cast))
+    }
+
     /// Consume n positional arguments if possible.
     pub fn consume(&mut self, n: usize) -> SourceResult<Vec<Arg>> {
         let mut list = vec![];
@@ -155,6 +180,22 @@ impl Args {
         }
     }
 
+    /// Append the arguments of `other` to these.
+    ///
+    /// Positional arguments keep their order. Named arguments that are already
+    /// present here take precedence, and the ones from `other` are discarded.
+    pub fn merge(&mut self, other: Args) {
+        let len = self.items.len();
+        for arg in other.items {
+            if arg.name.is_some()
+                && self.items[..len].iter().any(|item| item.name == arg.name)
+            {
+                continue;
+            }
+            self.items.push(arg);
+        }
+    }
+
     /// Take out all arguments into a new instance.
     pub fn take(&mut self) -> Self {
         Self {
@@ -166,11 +207,25 @@ impl Args {
     /// Return an "unexpected argument" error if there is any remaining
     /// argument.
     pub fn finish(self) -> SourceResult<()> {
+        self.finish_with(|| &[])
+    }
+
+    /// Same as finish, but suggests the most similar of the given parameter
+    /// names if there is an unexpected named argument, or lists the valid
+    /// ones if none is similar. The parameters are only requested in that
+    /// case.
+    pub fn finish_with<'a>(
+        self,
+        params: impl FnOnce() -> &'a [ParamInfo],
+    ) -> SourceResult<()> {
         if let Some(arg) = self.items.first() {
             bail!(
                 arg.span,
                 match &arg.name {
-                    Some(name) => eco_format!("unexpected argument: {}", name),
+                    Some(name) => match hint(name, params()) {
+                        Some(hint) => eco_format!("unexpected argument: {name} – {hint}"),
+                        None => eco_format!("unexpected argument: {}", name),
+                    },
                     _ => eco_format!("unexpected argument"),
                 }
             )
@@ -196,6 +251,60 @@ impl Args {
     }
 }
 
+/// How many valid names to list at most for an unexpected argument.
+const MAX_LISTED: usize = 5;
+
+/// Suggest the named parameter whose name is most similar to the given one,
+/// or list the valid names if none is similar enough.
+///
+/// Returns nothing if the name already is a parameter, since then the name
+/// isn't misspelled but the argument was left over for another reason.
+fn hint(name: &str, params: &[ParamInfo]) -> Option<EcoString> {
+    let named: Vec<_> = params
+        .iter()
+        .filter(|param| param.named)
+        .map(|param| param.name)
+        .collect();
+    if named.is_empty() || named.iter().any(|&param| param == name) {
+        return None;
+    }
+
+    let limit = (name.chars().count() / 3).max(1);
+    let similar = named
+        .iter()
+        .map(|&param| (edit_distance(name, param), param))
+        .filter(|&(distance, _)| distance <= limit)
+        .min_by_key(|&(distance, _)| distance);
+
+    if let Some((_, similar)) = similar {
+        return Some(eco_format!("did you mean {}?", similar));
+    }
+
+    let mut listed = named;
+    if listed.len() > MAX_LISTED {
+        listed.truncate(MAX_LISTED);
+        listed.push("more");
+    }
+
+    Some(eco_format!("valid arguments are {}", separated_list(&listed, "and")))
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 impl Debug for Args {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let pieces: Vec<_> =
@@ -213,3 +322,53 @@ impl Debug for Arg {
         Debug::fmt(&self.value.v, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::array;
+
+    fn named(name: &str, value: impl Into<Value>) -> Arg {
+        let span = Span::detached();
+        Arg {
+            span,
+            name: Some(name.into()),
+            value: Spanned::new(value.into(), span),
+        }
+    }
+
+    #[test]
+    fn test_args_merge() {
+        let mut args = Args::new(Span::detached(), [Value::Int(1)]);
+        args.items.push(named("fill", "red"));
+
+        let mut other = Args::new(Span::detached(), [Value::Int(2)]);
+        other.items.push(named("fill", "blue"));
+        other.items.push(named("stroke", "green"));
+
+        args.merge(other);
+        assert_eq!(args.items.len(), 4);
+        assert_eq!(args.to_pos(), array![1, 2]);
+        assert_eq!(args.named::<Str>("fill").unwrap(), Some("red".into()));
+        assert_eq!(args.named::<Str>("stroke").unwrap(), Some("green".into()));
+    }
+
+    #[test]
+    fn test_args_eat_if() {
+        let mut args = Args::new(Span::detached(), [Value::Int(0), Value::Int(5)]);
+        args.items.insert(0, named("fill", "red"));
+
+        // The predicate rejects the first positional argument.
+        assert_eq!(args.eat_if::<i64>(|&v| v > 0).unwrap(), None);
+        assert_eq!(args.items.len(), 3);
+
+        // The first positional argument is not castable.
+        assert_eq!(args.eat_if::<Str>(|_| true).unwrap(), None);
+        assert_eq!(args.items.len(), 3);
+
+        // The predicate accepts it.
+        assert_eq!(args.eat_if::<i64>(|&v| v == 0).unwrap(), Some(0));
+        assert_eq!(args.to_pos(), array![5]);
+        assert_eq!(args.items.len(), 2);
+    }
+}
@@ -1,6 +1,6 @@
 pub use typst_macros::{cast_from_value, cast_to_value, Cast};
 
-use std::num::{NonZeroI64, NonZeroU64, NonZeroUsize};
+use std::num::{NonZeroI64, NonZeroU32, NonZeroU64, NonZeroUsize};
 use std::ops::Add;
 
 use ecow::EcoString;
@@ -129,6 +129,23 @@ cast_to_value! {
     v: i32 => Value::Int(v as i64)
 }
 
+cast_from_value! {
+    f32,
+    // Rounds to the nearest `f32`. Infinity and NaN carry over, but finite
+    // values that don't fit are rejected.
+    float: f64 => {
+        let narrowed = float as f32;
+        if float.is_finite() && !narrowed.is_finite() {
+            return Err("number out of range".into());
+        }
+        narrowed
+    },
+}
+
+cast_to_value! {
+    v: f32 => Value::Float(v as f64)
+}
+
 cast_from_value! {
     NonZeroI64,
     int: i64 => int.try_into()
@@ -143,6 +160,22 @@ cast_to_value! {
     v: NonZeroI64 => Value::Int(v.get())
 }
 
+cast_from_value! {
+    NonZeroU32,
+    int: i64 => int
+        .try_into()
+        .and_then(|int: u32| int.try_into())
+        .map_err(|_| if int <= 0 {
+            "number must be positive"
+        } else {
+            "number too large"
+        })?,
+}
+
+cast_to_value! {
+    v: NonZeroU32 => Value::Int(v.get() as i64)
+}
+
 cast_from_value! {
     NonZeroU64,
     int: i64 => int
@@ -389,3 +422,35 @@ impl Cast for Never {
         CastInfo::Union(vec![])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    #[track_caller]
+    fn test_error<T: Cast + Debug>(value: impl Into<Value>, exp: &str) {
+        assert_eq!(T::cast(value.into()).unwrap_err().as_str(), exp);
+    }
+
+    #[test]
+    fn test_cast_non_zero_u32() {
+        assert_eq!(NonZeroU32::cast(Value::Int(3)), Ok(NonZeroU32::new(3).unwrap()));
+        test_error::<NonZeroU32>(0, "number must be positive");
+        test_error::<NonZeroU32>(-1, "number must be positive");
+        test_error::<NonZeroU32>(i64::from(u32::MAX) + 1, "number too large");
+    }
+
+    #[test]
+    fn test_cast_f32() {
+        assert_eq!(f32::cast(Value::Float(0.5)), Ok(0.5));
+        assert_eq!(f32::cast(Value::Int(2)), Ok(2.0));
+        assert_eq!(f32::cast(Value::Float(0.1)), Ok(0.1_f32));
+        assert_eq!(f32::cast(Value::Float(f64::INFINITY)), Ok(f32::INFINITY));
+        assert_eq!(f32::cast(Value::Float(f64::NEG_INFINITY)), Ok(f32::NEG_INFINITY));
+        assert!(f32::cast(Value::Float(f64::NAN)).unwrap().is_nan());
+        test_error::<f32>(1e300, "number out of range");
+        test_error::<f32>(-1e300, "number out of range");
+    }
+}
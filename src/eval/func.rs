@@ -85,12 +85,12 @@ impl Func {
         match &self.repr {
             Repr::Native(native) => {
                 let value = (native.func)(vm, &mut args)?;
-                args.finish()?;
+                args.finish_with(|| &native.info.params)?;
                 Ok(value)
             }
             Repr::Elem(func) => {
                 let value = func.construct(vm, &mut args)?;
-                args.finish()?;
+                args.finish_with(|| &func.info().params)?;
                 Ok(Value::Content(value))
             }
             Repr::Closure(closure) => {
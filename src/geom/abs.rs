@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use super::*;
 
 /// An absolute length.
@@ -214,6 +216,37 @@ impl<'a> Sum<&'a Self> for Abs {
     }
 }
 
+impl FromStr for Abs {
+    type Err = &'static str;
+
+    /// Parses an absolute length from strings like the following:
+    /// - `12pt`, `2.5cm`, `3mm`, `1in` (with unit),
+    /// - `10` (without unit, interpreted as points).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("length string is empty");
+        }
+
+        let count = s.chars().rev().take_while(char::is_ascii_alphabetic).count();
+        let (number, unit) = s.split_at(s.len() - count);
+        let unit = match unit {
+            "" | "pt" => AbsUnit::Pt,
+            "mm" => AbsUnit::Mm,
+            "cm" => AbsUnit::Cm,
+            "in" => AbsUnit::In,
+            _ => return Err("length string has invalid unit"),
+        };
+
+        let value = number.parse().map_err(|_| "length string has invalid number")?;
+        let length = Self::with_unit(value, unit);
+        if !length.to_raw().is_finite() {
+            return Err("length string is out of range");
+        }
+
+        Ok(length)
+    }
+}
+
 cast_to_value! {
     v: Abs => Value::Length(v.into())
 }
@@ -262,4 +295,18 @@ mod tests {
     fn test_length_unit_conversion() {
         assert!((Abs::mm(150.0).to_cm() - 15.0) < 1e-4);
     }
+
+    #[test]
+    fn test_parse_length_strings() {
+        assert_eq!("12pt".parse(), Ok(Abs::pt(12.0)));
+        assert_eq!("2.5cm".parse(), Ok(Abs::cm(2.5)));
+        assert_eq!("3mm".parse(), Ok(Abs::mm(3.0)));
+        assert_eq!("1in".parse(), Ok(Abs::inches(1.0)));
+        assert_eq!("10".parse(), Ok(Abs::pt(10.0)));
+        assert_eq!("".parse::<Abs>(), Err("length string is empty"));
+        assert_eq!("abc".parse::<Abs>(), Err("length string has invalid unit"));
+        assert_eq!("1.2.3pt".parse::<Abs>(), Err("length string has invalid number"));
+        assert_eq!("1e999pt".parse::<Abs>(), Err("length string is out of range"));
+        assert_eq!("1e308in".parse::<Abs>(), Err("length string is out of range"));
+    }
 }
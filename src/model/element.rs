@@ -85,7 +85,7 @@ impl ElemFunc {
     /// Execute the set rule for the element and return the resulting style map.
     pub fn set(self, mut args: Args) -> SourceResult<Styles> {
         let styles = (self.0.set)(&mut args)?;
-        args.finish()?;
+        args.finish_with(|| &self.info().params)?;
         Ok(styles)
     }
 }
@@ -9,7 +9,8 @@ use ecow::EcoString;
 use unscanny::Scanner;
 
 use super::{
-    is_id_continue, is_id_start, is_newline, split_newlines, Span, SyntaxKind, SyntaxNode,
+    is_id_continue, is_id_start, is_newline, split_newlines, strip_separators, Span,
+    SyntaxKind, SyntaxNode,
 };
 use crate::geom::{AbsUnit, AngleUnit};
 use crate::util::NonZeroExt;
@@ -942,7 +943,7 @@ node! {
 impl Int {
     /// Get the integer value.
     pub fn get(&self) -> i64 {
-        let text = self.0.text();
+        let text = strip_separators(self.0.text());
         if let Some(rest) = text.strip_prefix("0x") {
             i64::from_str_radix(rest, 16)
         } else if let Some(rest) = text.strip_prefix("0o") {
@@ -964,7 +965,7 @@ node! {
 impl Float {
     /// Get the floating-point value.
     pub fn get(&self) -> f64 {
-        self.0.text().parse().unwrap_or_default()
+        strip_separators(self.0.text()).parse().unwrap_or_default()
     }
 }
 
@@ -984,7 +985,7 @@ impl Numeric {
             .count();
 
         let split = text.len() - count;
-        let value = text[..split].parse().unwrap_or_default();
+        let value = strip_separators(&text[..split]).parse().unwrap_or_default();
         let unit = match &text[split..] {
             "pt" => Unit::Length(AbsUnit::Pt),
             "mm" => Unit::Length(AbsUnit::Mm),
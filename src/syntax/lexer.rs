@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use ecow::{eco_format, EcoString};
 use unicode_ident::{is_xid_continue, is_xid_start};
 use unicode_segmentation::UnicodeSegmentation;
@@ -212,11 +214,7 @@ impl Lexer<'_> {
                 return self.error_at_end("expected closing brace");
             }
 
-            if u32::from_str_radix(hex, 16)
-                .ok()
-                .and_then(std::char::from_u32)
-                .is_none()
-            {
+            if !is_codepoint(hex) {
                 return self.error(eco_format!("invalid unicode codepoint: {}", hex));
             }
 
@@ -552,11 +550,7 @@ impl Lexer<'_> {
         }
 
         // Read the first part (integer or fractional depending on `first`).
-        self.s.eat_while(if base == 16 {
-            char::is_ascii_alphanumeric
-        } else {
-            char::is_ascii_digit
-        });
+        self.digits(base == 16);
 
         // Read the fractional part if not already done.
         // Make sure not to confuse a range for the decimal separator.
@@ -566,13 +560,13 @@ impl Lexer<'_> {
             && self.s.eat_if('.')
             && base == 10
         {
-            self.s.eat_while(char::is_ascii_digit);
+            self.digits(false);
         }
 
         // Read the exponent.
         if !self.s.at("em") && self.s.eat_if(['e', 'E']) && base == 10 {
             self.s.eat_if(['+', '-']);
-            self.s.eat_while(char::is_ascii_digit);
+            self.digits(false);
         }
 
         // Read the suffix.
@@ -584,9 +578,13 @@ impl Lexer<'_> {
         let number = self.s.get(start..suffix_start);
         let suffix = self.s.from(suffix_start);
 
-        let kind = if i64::from_str_radix(number, base).is_ok() {
+        let digits = strip_separators(number);
+        let kind = if i64::from_str_radix(&digits, base).is_ok() {
             SyntaxKind::Int
-        } else if base == 10 && number.parse::<f64>().is_ok() {
+        } else if let (10, Ok(float)) = (base, digits.parse::<f64>()) {
+            if !float.is_finite() {
+                return self.error("number out of range");
+            }
             SyntaxKind::Float
         } else {
             return self.error(match base {
@@ -611,7 +609,42 @@ impl Lexer<'_> {
         SyntaxKind::Numeric
     }
 
+    /// Eat a run of digits. Single underscores are allowed as digit
+    /// separators if they are surrounded by digits: `1_000_000`.
+    fn digits(&mut self, hex: bool) {
+        let is_digit = |c: char| {
+            if hex {
+                c.is_ascii_alphanumeric()
+            } else {
+                c.is_ascii_digit()
+            }
+        };
+
+        // The first digit may already have been eaten by the caller, so look
+        // at the previous character instead of the number of eaten ones.
+        let is_prev_digit = |c: char| {
+            if hex {
+                c.is_ascii_hexdigit()
+            } else {
+                c.is_ascii_digit()
+            }
+        };
+
+        loop {
+            self.s.eat_while(is_digit);
+            if self.s.scout(-1).map_or(false, is_prev_digit)
+                && self.s.at('_')
+                && self.s.scout(1).map_or(false, is_digit)
+            {
+                self.s.eat();
+            } else {
+                break;
+            }
+        }
+    }
+
     fn string(&mut self) -> SyntaxKind {
+        let start = self.s.cursor();
         let mut escaped = false;
         self.s.eat_until(|c| {
             let stop = c == '"' && !escaped;
@@ -619,14 +652,57 @@ impl Lexer<'_> {
             stop
         });
 
+        let content = self.s.from(start);
         if !self.s.eat_if('"') {
             return self.error_at_end("expected quote");
         }
 
+        // Check that unicode escapes are valid. Other unknown escapes are
+        // passed through verbatim (e.g. for regexes).
+        let mut s = Scanner::new(content);
+        while let Some(c) = s.eat() {
+            if c != '\\' {
+                continue;
+            }
+
+            if !s.eat_if("u{") {
+                s.eat();
+                continue;
+            }
+
+            let hex = s.eat_while(char::is_ascii_alphanumeric);
+            if !s.eat_if('}') {
+                return self.error("expected closing brace");
+            }
+
+            if !is_codepoint(hex) {
+                return self.error(eco_format!("invalid unicode codepoint: {}", hex));
+            }
+        }
+
         SyntaxKind::Str
     }
 }
 
+/// Remove the digit separators from a number: `1_000` -> `1000`.
+pub(super) fn strip_separators(text: &str) -> Cow<str> {
+    if text.contains('_') {
+        Cow::Owned(text.replace('_', ""))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Whether the hex digits of a unicode escape like `\u{1F600}` denote a
+/// valid codepoint. At most six digits are allowed.
+fn is_codepoint(hex: &str) -> bool {
+    hex.len() <= 6
+        && u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(std::char::from_u32)
+            .is_some()
+}
+
 /// Try to parse an identifier into a keyword.
 fn keyword(ident: &str) -> Option<SyntaxKind> {
     Some(match ident {
@@ -31,6 +31,10 @@ fn markup(
     min_indent: usize,
     mut stop: impl FnMut(&Parser) -> bool,
 ) {
+    if !p.descend() {
+        return;
+    }
+
     let m = p.marker();
     let mut nesting: usize = 0;
     while !p.eof() {
@@ -57,6 +61,7 @@ fn markup(
         }
     }
     p.wrap(m, SyntaxKind::Markup);
+    p.ascend();
 }
 
 pub(super) fn reparse_markup(
@@ -243,6 +248,10 @@ fn math_expr(p: &mut Parser) {
 }
 
 fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
+    if !p.descend() {
+        return;
+    }
+
     let m = p.marker();
     let mut continuable = false;
     match p.current() {
@@ -337,6 +346,8 @@ fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
 
         p.wrap(m, kind);
     }
+
+    p.ascend();
 }
 
 fn maybe_delimited(p: &mut Parser, allow_fence: bool) -> bool {
@@ -587,6 +598,10 @@ fn code_expr_prec(
     min_prec: usize,
     allow_destructuring: bool,
 ) {
+    if !p.descend() {
+        return;
+    }
+
     let m = p.marker();
     if let (false, Some(op)) = (atomic, ast::UnOp::from_kind(p.current())) {
         p.eat();
@@ -648,6 +663,8 @@ fn code_expr_prec(
 
         break;
     }
+
+    p.ascend();
 }
 
 fn code_primary(p: &mut Parser, atomic: bool, allow_destructuring: bool) {
@@ -1364,6 +1381,10 @@ fn validate_pattern<'a>(
     }
 }
 
+/// The maximum nesting depth of markup, math and code expressions. Deeper
+/// input is rejected instead of overflowing the stack.
+pub(super) const MAX_DEPTH: usize = 256;
+
 /// Manages parsing of a stream of tokens.
 struct Parser<'s> {
     text: &'s str,
@@ -1375,6 +1396,7 @@ struct Parser<'s> {
     nodes: Vec<SyntaxNode>,
     stop_at_newline: Vec<bool>,
     balanced: bool,
+    depth: usize,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -1395,6 +1417,7 @@ impl<'s> Parser<'s> {
             nodes: vec![],
             stop_at_newline: vec![],
             balanced: true,
+            depth: 0,
         }
     }
 
@@ -1605,6 +1628,31 @@ impl<'s> Parser<'s> {
         self.nodes.insert(m.0, error);
     }
 
+    /// Enter a nested expression. If the maximum depth is reached, the
+    /// current token is turned into an error instead and `false` is returned.
+    fn descend(&mut self) -> bool {
+        if self.depth < MAX_DEPTH {
+            self.depth += 1;
+            return true;
+        }
+
+        self.balanced = false;
+        let message = eco_format!("maximum nesting depth of {MAX_DEPTH} exceeded");
+        if self.eof() {
+            self.nodes.push(SyntaxNode::error(message, "", ErrorPos::Full));
+        } else {
+            let offset = self.nodes.len();
+            self.eat();
+            self.nodes[offset].convert_to_error(message);
+        }
+        false
+    }
+
+    /// Leave a nested expression.
+    fn ascend(&mut self) {
+        self.depth -= 1;
+    }
+
     fn unexpected(&mut self) {
         self.unskip();
         while self
@@ -1627,3 +1675,31 @@ impl<'s> Parser<'s> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exceeds_depth(text: &str) -> bool {
+        let root = parse(text);
+        assert_eq!(root.len(), text.len());
+        let message = eco_format!("maximum nesting depth of {MAX_DEPTH} exceeded");
+        root.errors().iter().any(|error| error.message == message)
+    }
+
+    #[test]
+    fn test_parse_depth_limit() {
+        // The markup and the innermost number take up one level each.
+        let nested = |n: usize| format!("#{}1{}", "(".repeat(n), ")".repeat(n));
+        assert!(!parse(&nested(MAX_DEPTH - 2)).erroneous());
+        assert!(exceeds_depth(&nested(MAX_DEPTH - 1)));
+    }
+
+    #[test]
+    fn test_parse_deeply_nested() {
+        let n = 10_000;
+        assert!(exceeds_depth(&format!("#{}1{}", "(".repeat(n), ")".repeat(n))));
+        assert!(exceeds_depth(&format!("{}1{}", "#[".repeat(n), "]".repeat(n))));
+        assert!(exceeds_depth(&format!("${}{}$", "(".repeat(n), ")".repeat(n))));
+    }
+}
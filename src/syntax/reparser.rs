@@ -2,6 +2,7 @@ use std::ops::Range;
 
 use super::{
     is_newline, parse, reparse_block, reparse_markup, Span, SyntaxKind, SyntaxNode,
+    MAX_DEPTH,
 };
 
 /// Refresh the given syntax node with as little parsing as possible.
@@ -18,7 +19,7 @@ pub fn reparse(
     replaced: Range<usize>,
     replacement_len: usize,
 ) -> Range<usize> {
-    try_reparse(text, replaced, replacement_len, None, root, 0).unwrap_or_else(|| {
+    try_reparse(text, replaced, replacement_len, None, root, 0, 1).unwrap_or_else(|| {
         let id = root.span().source();
         *root = parse(text);
         root.numberize(id, Span::FULL).unwrap();
@@ -26,7 +27,7 @@ pub fn reparse(
     })
 }
 
-/// Try to reparse inside the given node.
+/// Try to reparse inside the given node, which is `depth` levels deep.
 fn try_reparse(
     text: &str,
     replaced: Range<usize>,
@@ -34,6 +35,7 @@ fn try_reparse(
     parent_kind: Option<SyntaxKind>,
     node: &mut SyntaxNode,
     offset: usize,
+    depth: usize,
 ) -> Option<Range<usize>> {
     // The range of children which overlap with the edit.
     #[allow(clippy::reversed_empty_ranges)]
@@ -60,6 +62,7 @@ fn try_reparse(
                 Some(node_kind),
                 child,
                 cursor,
+                depth + 1,
             ) {
                 assert_eq!(child.len(), new_len);
                 let new_desc = child.descendants();
@@ -69,7 +72,9 @@ fn try_reparse(
 
             // If the child is a block, try to reparse the block.
             if child.kind().is_block() {
-                if let Some(newborn) = reparse_block(text, new_range.clone()) {
+                if let Some(newborn) = reparse_block(text, new_range.clone())
+                    .filter(|newborn| within_depth_limit(depth, [newborn]))
+                {
                     return node
                         .replace_children(i..i + 1, vec![newborn])
                         .is_ok()
@@ -170,7 +175,9 @@ fn try_reparse(
             |kind| kind == stop_kind,
         );
 
-        if let Some(newborns) = reparsed {
+        if let Some(newborns) =
+            reparsed.filter(|newborns| within_depth_limit(depth, newborns))
+        {
             // If more children follow, at_start must match its previous value.
             // Similarly, if we children follow or we not top-level the nesting
             // must match its previous value.
@@ -196,6 +203,24 @@ fn try_reparse(
     None
 }
 
+/// Whether nodes that were reparsed below a node at the given depth are
+/// guaranteed to be unaffected by the parser's nesting limit.
+///
+/// The reparser starts with a fresh nesting depth, so it would accept input
+/// that a full parse rejects. Since the parser never nests deeper than the
+/// tree, we can fall back to a full parse whenever the tree gets close to
+/// the limit.
+fn within_depth_limit<'a>(
+    depth: usize,
+    newborns: impl IntoIterator<Item = &'a SyntaxNode>,
+) -> bool {
+    fn height(node: &SyntaxNode) -> usize {
+        1 + node.children().map(height).max().unwrap_or(0)
+    }
+
+    depth + newborns.into_iter().map(height).max().unwrap_or(0) < MAX_DEPTH
+}
+
 /// Whether the inner range is fully contained in the outer one (no touching).
 fn includes(outer: &Range<usize>, inner: &Range<usize>) -> bool {
     outer.start < inner.start && outer.end > inner.end
@@ -1,4 +1,4 @@
-use std::fmt::{self, Debug, Formatter};
+use std::fmt::{self, Debug, Display, Formatter};
 use std::num::NonZeroU64;
 use std::ops::Range;
 
@@ -99,6 +99,11 @@ impl<T> Spanned<T> {
         Spanned { v: &self.v, span: self.span }
     }
 
+    /// Convert from `&mut Spanned<T>` to `Spanned<&mut T>`
+    pub fn as_mut(&mut self) -> Spanned<&mut T> {
+        Spanned { v: &mut self.v, span: self.span }
+    }
+
     /// Map the value using a function.
     pub fn map<F, U>(self, f: F) -> Spanned<U>
     where
@@ -114,9 +119,19 @@ impl<T: Debug> Debug for Spanned<T> {
     }
 }
 
+/// Displays only the value. Formatting flags like `{:#}` or `{:.2}` are
+/// passed through to it.
+impl<T: Display> Display for Spanned<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.v.fmt(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{SourceId, Span};
+    use std::fmt::{self, Display, Formatter};
+
+    use super::{SourceId, Span, Spanned};
 
     #[test]
     fn test_span_encoding() {
@@ -125,4 +140,33 @@ mod tests {
         assert_eq!(span.source(), id);
         assert_eq!(span.number(), 10);
     }
+
+    #[test]
+    fn test_spanned_as_mut() {
+        let span = Span::new(SourceId::from_u16(1), 3);
+        let mut spanned = Spanned::new(1, span);
+        let borrowed = spanned.as_mut();
+        assert_eq!(borrowed.span, span);
+        *borrowed.v += 1;
+        assert_eq!(spanned.v, 2);
+    }
+
+    #[test]
+    fn test_spanned_display() {
+        struct Alternate;
+
+        impl Display for Alternate {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str(if f.alternate() { "alternate" } else { "plain" })
+            }
+        }
+
+        let spanned = Spanned::new(1.5, Span::detached());
+        assert_eq!(spanned.to_string(), "1.5");
+        assert_eq!(format!("{:.2}", spanned), "1.50");
+
+        let spanned = Spanned::new(Alternate, Span::detached());
+        assert_eq!(format!("{}", spanned), "plain");
+        assert_eq!(format!("{:#}", spanned), "alternate");
+    }
 }
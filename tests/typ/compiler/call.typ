@@ -47,6 +47,18 @@
 // Error: 26-30 duplicate argument: font
 #set text(font: "Arial", font: "Helvetica")
 
+---
+// Error: 7-15 unexpected argument: fil – did you mean fill?
+#rect(fil: red)
+
+---
+// Error: 11-21 unexpected argument: sise – did you mean size?
+#set text(sise: 12pt)
+
+---
+// Error: 7-18 unexpected argument: colour – valid arguments are start, end, length, angle, and stroke
+#line(colour: red)
+
 ---
 // Error: 2-6 expected function, found boolean
 #true()
@@ -120,6 +120,36 @@
 #test(0b1101, 13)
 #test(0xA + 0xa, 0x14)
 
+---
+// Test digit separators.
+#test(1_000, 1000)
+#test(1_000_000, 1000000)
+#test(12_345.678, 12345.678)
+#test(0xFF_FF, 0xFFFF)
+#test(1_0e1_0, 10e10)
+#test(2_500pt, 2500pt)
+#test(0_1, 1)
+#test(1_0pt, 10pt)
+#test(.5_0, 0.5)
+
+---
+// A leading underscore makes an identifier.
+// Error: 2-4 unknown variable: _1
+#_1
+
+---
+// A trailing underscore is not part of the number.
+// Error: 4 expected comma
+// Error: 4-5 expected expression, found underscore
+#(1_)
+
+---
+// An underscore next to the decimal point is not part of the number.
+// Error: 4 expected comma
+// Error: 4-5 expected expression, found underscore
+// Error: 5 expected comma
+#(1_.0)
+
 ---
 // Error: 2-7 invalid binary number: 0b123
 #0b123
@@ -128,6 +158,14 @@
 // Error: 2-8 invalid hexadecimal number: 0x123z
 #0x123z
 
+---
+// Error: 2-7 number out of range
+#1e999
+
+---
+// Error: 2-402 number out of range
+#9999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999
+
 ---
 // Test boolean operators.
 
@@ -206,3 +206,22 @@
 ---
 // Error: 2:1 expected quote
 #"hello\"
+
+---
+// Test unicode escapes.
+#test("\u{1F600}", "😀")
+#test("\u{41}\u{62}c", "Abc")
+#test("\u{000041}", "A")
+#test("\\u{D800}".len(), 8)
+
+---
+// Error: 2-12 invalid unicode codepoint: D800
+#"\u{D800}"
+
+---
+// Error: 2-15 invalid unicode codepoint: 0000041
+#"\u{0000041}"
+
+---
+// Error: 2-9 expected closing brace
+#"\u{41"
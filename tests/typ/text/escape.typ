@@ -30,6 +30,11 @@ let f() , ; : | + - /= == 12 "string"
 // Error: 1-11 invalid unicode codepoint: FFFFFF
 \u{FFFFFF}
 
+---
+// Unicode codepoint has too many digits.
+// Error: 1-12 invalid unicode codepoint: 0000041
+\u{0000041}
+
 ---
 // Unterminated.
 // Error: 6 expected closing brace
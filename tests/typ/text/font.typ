@@ -56,5 +56,5 @@ Emoji: 🐪, 🌋, 🏞
 #set text(size: 10pt, 12pt)
 
 ---
-// Error: 11-31 unexpected argument: something
+// Error: 11-31 unexpected argument: something – valid arguments are font, fallback, style, weight, stretch, and more
 #set text(something: "invalid")